@@ -0,0 +1,30 @@
+use actix_web::{web, HttpResponse};
+use mime_guess::from_path;
+use rust_embed::RustEmbed;
+
+/// The built frontend (`frontend/dist`), embedded into the binary at compile
+/// time so GroceryNana can ship as a single self-contained executable.
+#[derive(RustEmbed)]
+#[folder = "../frontend/dist/"]
+struct Frontend;
+
+/// Catch-all `GET /{filename:.*}` handler: serves the embedded asset matching
+/// the request path, or falls back to `index.html` so client-side routing on
+/// deep links (e.g. `/lists/42`) still resolves. `/api/*` routes are
+/// registered before this one and take priority.
+pub async fn spa_fallback(path: web::Path<String>) -> HttpResponse {
+    let filename = path.into_inner();
+    let filename = filename.trim_start_matches('/');
+
+    match Frontend::get(filename) {
+        Some(asset) => HttpResponse::Ok()
+            .content_type(from_path(filename).first_or_octet_stream().as_ref())
+            .body(asset.data.into_owned()),
+        None => match Frontend::get("index.html") {
+            Some(asset) => HttpResponse::Ok()
+                .content_type("text/html; charset=utf-8")
+                .body(asset.data.into_owned()),
+            None => HttpResponse::NotFound().finish(),
+        },
+    }
+}
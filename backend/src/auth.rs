@@ -0,0 +1,223 @@
+use std::future::{ready, Ready};
+use std::rc::Rc;
+
+use actix_web::body::EitherBody;
+use actix_web::dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::{web, Error, HttpMessage, HttpResponse};
+use chrono::{Duration, Utc};
+use futures_util::future::LocalBoxFuture;
+use serde::{Deserialize, Serialize};
+use sqlx::{Pool, Sqlite};
+use uuid::Uuid;
+
+const SESSION_TTL_DAYS: i64 = 7;
+
+#[derive(Debug, Deserialize, ts_rs::TS)]
+#[ts(export)]
+pub struct RegisterRequest {
+    pub email: String,
+    pub password: String,
+}
+
+#[derive(Debug, Serialize, ts_rs::TS)]
+#[ts(export)]
+pub struct RegisterResponse {
+    pub id: String,
+    pub email: String,
+}
+
+#[derive(Debug, Deserialize, ts_rs::TS)]
+#[ts(export)]
+pub struct LoginRequest {
+    pub email: String,
+    pub password: String,
+}
+
+#[derive(Debug, Serialize, ts_rs::TS)]
+#[ts(export)]
+pub struct LoginResponse {
+    pub token: String,
+}
+
+#[derive(Debug, Serialize, ts_rs::TS)]
+#[ts(export)]
+pub struct AuthErrorResponse {
+    pub message: String,
+}
+
+/// The user a validated session token resolves to, inserted into request
+/// extensions by `AuthMiddleware` so protected handlers can pull it out.
+#[derive(Debug, Clone)]
+pub struct AuthenticatedUser {
+    pub id: String,
+    pub email: String,
+}
+
+pub async fn register(
+    db: web::Data<Pool<Sqlite>>,
+    body: web::Json<RegisterRequest>,
+) -> actix_web::Result<HttpResponse> {
+    let password_hash = match bcrypt::hash(&body.password, bcrypt::DEFAULT_COST) {
+        Ok(hash) => hash,
+        Err(_) => {
+            return Ok(HttpResponse::InternalServerError().json(AuthErrorResponse {
+                message: "Failed to hash password".to_string(),
+            }))
+        }
+    };
+    let id = Uuid::new_v4().to_string();
+
+    let result = sqlx::query("INSERT INTO users (id, email, password_hash) VALUES (?, ?, ?)")
+        .bind(&id)
+        .bind(&body.email)
+        .bind(&password_hash)
+        .execute(db.as_ref())
+        .await;
+
+    match result {
+        Ok(_) => Ok(HttpResponse::Created().json(RegisterResponse {
+            id,
+            email: body.email.clone(),
+        })),
+        Err(_) => Ok(HttpResponse::Conflict().json(AuthErrorResponse {
+            message: "Email is already registered".to_string(),
+        })),
+    }
+}
+
+pub async fn login(
+    db: web::Data<Pool<Sqlite>>,
+    body: web::Json<LoginRequest>,
+) -> actix_web::Result<HttpResponse> {
+    let user: Option<(String, String)> =
+        sqlx::query_as("SELECT id, password_hash FROM users WHERE email = ?")
+            .bind(&body.email)
+            .fetch_optional(db.as_ref())
+            .await
+            .unwrap_or(None);
+
+    let Some((user_id, password_hash)) = user else {
+        return Ok(HttpResponse::Unauthorized().json(AuthErrorResponse {
+            message: "Invalid email or password".to_string(),
+        }));
+    };
+
+    match bcrypt::verify(&body.password, &password_hash) {
+        Ok(true) => {}
+        _ => {
+            return Ok(HttpResponse::Unauthorized().json(AuthErrorResponse {
+                message: "Invalid email or password".to_string(),
+            }))
+        }
+    }
+
+    let token = Uuid::new_v4().to_string();
+    let expires_at = Utc::now() + Duration::days(SESSION_TTL_DAYS);
+
+    sqlx::query("INSERT INTO sessions (token, user_id, expires_at) VALUES (?, ?, ?)")
+        .bind(&token)
+        .bind(&user_id)
+        .bind(expires_at.to_rfc3339())
+        .execute(db.as_ref())
+        .await
+        .ok();
+
+    Ok(HttpResponse::Ok().json(LoginResponse { token }))
+}
+
+/// Middleware that looks up the `Authorization: Bearer <token>` header
+/// against the `sessions` table and rejects the request with 401 if it is
+/// missing, unknown, or expired. On success it stashes an `AuthenticatedUser`
+/// in the request extensions for downstream handlers.
+pub struct AuthMiddleware;
+
+impl<S, B> Transform<S, ServiceRequest> for AuthMiddleware
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Transform = AuthMiddlewareService<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(AuthMiddlewareService {
+            service: Rc::new(service),
+        }))
+    }
+}
+
+pub struct AuthMiddlewareService<S> {
+    service: Rc<S>,
+}
+
+impl<S, B> Service<ServiceRequest> for AuthMiddlewareService<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let service = Rc::clone(&self.service);
+
+        let token = req
+            .headers()
+            .get("Authorization")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .map(|value| value.to_string());
+
+        Box::pin(async move {
+            let Some(token) = token else {
+                return Ok(req
+                    .into_response(HttpResponse::Unauthorized().finish())
+                    .map_into_right_body());
+            };
+
+            let db = req.app_data::<web::Data<Pool<Sqlite>>>().cloned();
+            let Some(db) = db else {
+                return Ok(req
+                    .into_response(HttpResponse::InternalServerError().finish())
+                    .map_into_right_body());
+            };
+
+            let row: Option<(String, String, String)> = sqlx::query_as(
+                "SELECT users.id, users.email, sessions.expires_at \
+                 FROM sessions JOIN users ON users.id = sessions.user_id \
+                 WHERE sessions.token = ?",
+            )
+            .bind(&token)
+            .fetch_optional(db.as_ref())
+            .await
+            .unwrap_or(None);
+
+            let Some((user_id, email, expires_at)) = row else {
+                return Ok(req
+                    .into_response(HttpResponse::Unauthorized().finish())
+                    .map_into_right_body());
+            };
+
+            let expired = chrono::DateTime::parse_from_rfc3339(&expires_at)
+                .map(|expires_at| expires_at < Utc::now())
+                .unwrap_or(true);
+            if expired {
+                return Ok(req
+                    .into_response(HttpResponse::Unauthorized().finish())
+                    .map_into_right_body());
+            }
+
+            req.extensions_mut()
+                .insert(AuthenticatedUser { id: user_id, email });
+
+            let res = service.call(req).await?;
+            Ok(res.map_into_left_body())
+        })
+    }
+}
@@ -0,0 +1,155 @@
+use std::fs;
+use std::path::PathBuf;
+
+use clap::Parser;
+use serde::Deserialize;
+
+const DEFAULT_HOST: &str = "0.0.0.0";
+const DEFAULT_PORT: u16 = 8080;
+const DEFAULT_DATABASE_URL: &str = "sqlite:./database.db";
+const DEFAULT_REMINDER_SCAN_INTERVAL_SECS: u64 = 60 * 60;
+const DEFAULT_REMINDER_EXPIRY_THRESHOLD_DAYS: i64 = 3;
+
+/// GroceryNana backend server.
+#[derive(Debug, Parser)]
+#[command(author, version, about)]
+struct Cli {
+    /// Path to a TOML config file (see `[webserver]` and `[database]`).
+    #[arg(long)]
+    config: Option<PathBuf>,
+
+    /// Address to bind the webserver to.
+    #[arg(long)]
+    host: Option<String>,
+
+    /// Port to bind the webserver to.
+    #[arg(long)]
+    port: Option<u16>,
+
+    /// SQLite connection URL, e.g. `sqlite:./database.db`.
+    #[arg(long)]
+    database_url: Option<String>,
+
+    /// How often, in seconds, the expiry-reminder scanner wakes up.
+    #[arg(long)]
+    reminder_scan_interval_secs: Option<u64>,
+
+    /// How many days ahead of today an item's expiry date triggers a reminder.
+    #[arg(long)]
+    reminder_expiry_threshold_days: Option<i64>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct FileConfig {
+    webserver: Option<FileWebserverConfig>,
+    database: Option<FileDatabaseConfig>,
+    reminders: Option<FileRemindersConfig>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct FileWebserverConfig {
+    host: Option<String>,
+    port: Option<u16>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct FileDatabaseConfig {
+    url: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct FileRemindersConfig {
+    scan_interval_secs: Option<u64>,
+    expiry_threshold_days: Option<i64>,
+}
+
+/// Resolved server configuration, in precedence order
+/// CLI flags > environment variables > config file > built-in defaults.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub host: String,
+    pub port: u16,
+    pub database_url: String,
+    pub reminder_scan_interval_secs: u64,
+    pub reminder_expiry_threshold_days: i64,
+}
+
+impl Config {
+    pub fn bind_address(&self) -> String {
+        format!("{}:{}", self.host, self.port)
+    }
+
+    /// Parses CLI arguments and, if `--config` points at a TOML file, layers
+    /// it underneath environment variables and CLI flags.
+    pub fn load() -> Self {
+        let cli = Cli::parse();
+
+        let file_config = cli
+            .config
+            .as_ref()
+            .map(load_file_config)
+            .unwrap_or_default();
+        let file_webserver = file_config.webserver.unwrap_or_default();
+        let file_database = file_config.database.unwrap_or_default();
+        let file_reminders = file_config.reminders.unwrap_or_default();
+
+        let host = cli
+            .host
+            .or_else(|| std::env::var("WEBSERVER_HOST").ok())
+            .or(file_webserver.host)
+            .unwrap_or_else(|| DEFAULT_HOST.to_string());
+
+        let port = cli
+            .port
+            .or_else(|| std::env::var("WEBSERVER_PORT").ok().and_then(|p| p.parse().ok()))
+            .or(file_webserver.port)
+            .unwrap_or(DEFAULT_PORT);
+
+        let database_url = cli
+            .database_url
+            .or_else(|| std::env::var("DATABASE_URL").ok())
+            .or(file_database.url)
+            .unwrap_or_else(|| DEFAULT_DATABASE_URL.to_string());
+
+        let reminder_scan_interval_secs = cli
+            .reminder_scan_interval_secs
+            .or_else(|| {
+                std::env::var("REMINDER_SCAN_INTERVAL_SECS")
+                    .ok()
+                    .and_then(|v| v.parse().ok())
+            })
+            .or(file_reminders.scan_interval_secs)
+            .unwrap_or(DEFAULT_REMINDER_SCAN_INTERVAL_SECS);
+
+        let reminder_expiry_threshold_days = cli
+            .reminder_expiry_threshold_days
+            .or_else(|| {
+                std::env::var("REMINDER_EXPIRY_THRESHOLD_DAYS")
+                    .ok()
+                    .and_then(|v| v.parse().ok())
+            })
+            .or(file_reminders.expiry_threshold_days)
+            .unwrap_or(DEFAULT_REMINDER_EXPIRY_THRESHOLD_DAYS);
+
+        Config {
+            host,
+            port,
+            database_url,
+            reminder_scan_interval_secs,
+            reminder_expiry_threshold_days,
+        }
+    }
+}
+
+fn load_file_config(path: &PathBuf) -> FileConfig {
+    match fs::read_to_string(path) {
+        Ok(contents) => toml::from_str(&contents).unwrap_or_else(|err| {
+            log::warn!("Failed to parse config file {}: {err}", path.display());
+            FileConfig::default()
+        }),
+        Err(err) => {
+            log::warn!("Failed to read config file {}: {err}", path.display());
+            FileConfig::default()
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use actix_web::{web, HttpResponse, Result};
+use async_graphql::dataloader::{DataLoader, Loader};
+use async_graphql::http::{playground_source, GraphQLPlaygroundConfig};
+use async_graphql::{EmptyMutation, EmptySubscription, Object, Schema, SimpleObject};
+use async_graphql_actix_web::{GraphQLRequest, GraphQLResponse};
+use sqlx::{Pool, Sqlite};
+
+pub type GroceryNanaSchema = Schema<QueryRoot, EmptyMutation, EmptySubscription>;
+
+/// Id of a shopping list, used as the batch key for `ItemsByListLoader`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ListId(pub i64);
+
+#[derive(Debug, Clone)]
+pub struct ShoppingList {
+    pub id: i64,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, SimpleObject)]
+pub struct Item {
+    pub id: i64,
+    pub list_id: i64,
+    pub name: String,
+    pub quantity: i32,
+}
+
+/// Batches `items` lookups for a set of shopping lists into a single
+/// `SELECT ... WHERE list_id IN (...)` query instead of one query per list.
+pub struct ItemsByListLoader {
+    pool: Pool<Sqlite>,
+}
+
+impl ItemsByListLoader {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+}
+
+impl Loader<ListId> for ItemsByListLoader {
+    type Value = Vec<Item>;
+    type Error = Arc<sqlx::Error>;
+
+    async fn load(&self, keys: &[ListId]) -> Result<HashMap<ListId, Self::Value>, Self::Error> {
+        let ids: Vec<i64> = keys.iter().map(|k| k.0).collect();
+        let params = ids.iter().map(|_| "?").collect::<Vec<_>>().join(", ");
+        let query = format!(
+            "SELECT id, list_id, name, quantity FROM items WHERE list_id IN ({})",
+            params
+        );
+
+        let mut q = sqlx::query_as::<_, (i64, i64, String, i32)>(&query);
+        for id in &ids {
+            q = q.bind(id);
+        }
+
+        let rows = q.fetch_all(&self.pool).await.map_err(Arc::new)?;
+
+        let mut by_list: HashMap<ListId, Vec<Item>> = HashMap::new();
+        for (id, list_id, name, quantity) in rows {
+            by_list
+                .entry(ListId(list_id))
+                .or_default()
+                .push(Item {
+                    id,
+                    list_id,
+                    name,
+                    quantity,
+                });
+        }
+        Ok(by_list)
+    }
+}
+
+/// Shared state handed to every resolver: the pool for direct queries and the
+/// `DataLoader` for batched child lookups.
+pub struct QueryState {
+    pub pool: Pool<Sqlite>,
+    pub items_by_list: DataLoader<ItemsByListLoader>,
+}
+
+impl QueryState {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        let items_by_list = DataLoader::new(ItemsByListLoader::new(pool.clone()), tokio::spawn);
+        Self { pool, items_by_list }
+    }
+}
+
+pub struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    async fn lists(&self, ctx: &async_graphql::Context<'_>) -> async_graphql::Result<Vec<ShoppingList>> {
+        let state = ctx.data::<QueryState>()?;
+        let rows: Vec<(i64, String)> = sqlx::query_as("SELECT id, name FROM shopping_lists")
+            .fetch_all(&state.pool)
+            .await?;
+        Ok(rows
+            .into_iter()
+            .map(|(id, name)| ShoppingList { id, name })
+            .collect())
+    }
+
+    async fn list(
+        &self,
+        ctx: &async_graphql::Context<'_>,
+        id: i64,
+    ) -> async_graphql::Result<Option<ShoppingList>> {
+        let state = ctx.data::<QueryState>()?;
+        let row: Option<(i64, String)> =
+            sqlx::query_as("SELECT id, name FROM shopping_lists WHERE id = ?")
+                .bind(id)
+                .fetch_optional(&state.pool)
+                .await?;
+        Ok(row.map(|(id, name)| ShoppingList { id, name }))
+    }
+}
+
+#[Object]
+impl ShoppingList {
+    async fn id(&self) -> i64 {
+        self.id
+    }
+
+    async fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Resolved via `ItemsByListLoader` so fetching `items` for many lists in
+    /// one query only issues a single `IN (...)` lookup.
+    async fn items(&self, ctx: &async_graphql::Context<'_>) -> async_graphql::Result<Vec<Item>> {
+        let state = ctx.data::<QueryState>()?;
+        Ok(state
+            .items_by_list
+            .load_one(ListId(self.id))
+            .await?
+            .unwrap_or_default())
+    }
+}
+
+pub fn build_schema(pool: Pool<Sqlite>) -> GroceryNanaSchema {
+    Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .data(QueryState::new(pool))
+        .finish()
+}
+
+pub async fn graphql_index(schema: web::Data<GroceryNanaSchema>, req: GraphQLRequest) -> GraphQLResponse {
+    schema.execute(req.into_inner()).await.into()
+}
+
+pub async fn graphql_playground() -> Result<HttpResponse> {
+    Ok(HttpResponse::Ok()
+        .content_type("text/html; charset=utf-8")
+        .body(playground_source(GraphQLPlaygroundConfig::new(
+            "/api/graphql",
+        ))))
+}
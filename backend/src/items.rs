@@ -0,0 +1,205 @@
+use actix_web::{web, HttpResponse, Result};
+use serde::{Deserialize, Serialize};
+use sqlx::{Pool, Sqlite};
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, ts_rs::TS)]
+#[ts(export)]
+pub struct CreateItemRequest {
+    #[validate(length(min = 1, message = "Name must not be empty"))]
+    pub name: String,
+    #[validate(range(min = 1, message = "Quantity must be at least 1"))]
+    pub quantity: i32,
+    pub list_id: i64,
+    pub expiry_date: Option<String>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ts_rs::TS)]
+#[ts(export)]
+pub struct UpdateItemRequest {
+    #[validate(length(min = 1, message = "Name must not be empty"))]
+    pub name: String,
+    #[validate(range(min = 1, message = "Quantity must be at least 1"))]
+    pub quantity: i32,
+    pub expiry_date: Option<String>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Serialize, ts_rs::TS)]
+#[ts(export)]
+pub struct ItemResponse {
+    pub id: i64,
+    pub list_id: i64,
+    pub name: String,
+    pub quantity: i32,
+    pub expiry_date: Option<String>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Serialize, ts_rs::TS)]
+#[ts(export)]
+pub struct ValidationErrorResponse {
+    pub errors: Vec<String>,
+}
+
+fn validation_error_response(errors: validator::ValidationErrors) -> HttpResponse {
+    let messages = errors
+        .field_errors()
+        .into_values()
+        .flat_map(|errs| errs.iter())
+        .map(|err| {
+            err.message
+                .clone()
+                .map(|m| m.to_string())
+                .unwrap_or_else(|| err.code.to_string())
+        })
+        .collect();
+
+    HttpResponse::BadRequest().json(ValidationErrorResponse { errors: messages })
+}
+
+/// Runs free-text notes through an HTML sanitizer before they're persisted,
+/// so stored content is always safe to render back to the frontend.
+fn sanitize_notes(notes: Option<String>) -> Option<String> {
+    notes.map(|notes| ammonia::clean(&notes))
+}
+
+/// SQLite foreign keys are off by default and this pool never turns them on,
+/// so we check `list_id` ourselves instead of relying on a constraint error.
+async fn list_exists(db: &Pool<Sqlite>, list_id: i64) -> bool {
+    sqlx::query("SELECT 1 FROM shopping_lists WHERE id = ?")
+        .bind(list_id)
+        .fetch_optional(db)
+        .await
+        .ok()
+        .flatten()
+        .is_some()
+}
+
+pub async fn create_item(
+    db: web::Data<Pool<Sqlite>>,
+    body: web::Json<CreateItemRequest>,
+) -> Result<HttpResponse> {
+    if let Err(errors) = body.validate() {
+        return Ok(validation_error_response(errors));
+    }
+
+    if !list_exists(db.as_ref(), body.list_id).await {
+        return Ok(HttpResponse::BadRequest().json(ValidationErrorResponse {
+            errors: vec!["list_id does not refer to an existing shopping list".to_string()],
+        }));
+    }
+
+    let notes = sanitize_notes(body.notes.clone());
+
+    let result = sqlx::query(
+        "INSERT INTO items (list_id, name, quantity, expiry_date, notes) VALUES (?, ?, ?, ?, ?)",
+    )
+    .bind(body.list_id)
+    .bind(&body.name)
+    .bind(body.quantity)
+    .bind(&body.expiry_date)
+    .bind(&notes)
+    .execute(db.as_ref())
+    .await;
+
+    match result {
+        Ok(result) => Ok(HttpResponse::Created().json(ItemResponse {
+            id: result.last_insert_rowid(),
+            list_id: body.list_id,
+            name: body.name.clone(),
+            quantity: body.quantity,
+            expiry_date: body.expiry_date.clone(),
+            notes,
+        })),
+        Err(_) => Ok(HttpResponse::InternalServerError().finish()),
+    }
+}
+
+type ItemRow = (i64, i64, String, i32, Option<String>, Option<String>);
+
+pub async fn list_items(db: web::Data<Pool<Sqlite>>) -> Result<HttpResponse> {
+    let rows: Vec<ItemRow> = sqlx::query_as(
+        "SELECT id, list_id, name, quantity, expiry_date, notes FROM items",
+    )
+    .fetch_all(db.as_ref())
+    .await
+    .unwrap_or_default();
+
+    let items: Vec<ItemResponse> = rows
+        .into_iter()
+        .map(
+            |(id, list_id, name, quantity, expiry_date, notes)| ItemResponse {
+                id,
+                list_id,
+                name,
+                quantity,
+                expiry_date,
+                notes,
+            },
+        )
+        .collect();
+
+    Ok(HttpResponse::Ok().json(items))
+}
+
+pub async fn update_item(
+    db: web::Data<Pool<Sqlite>>,
+    path: web::Path<i64>,
+    body: web::Json<UpdateItemRequest>,
+) -> Result<HttpResponse> {
+    if let Err(errors) = body.validate() {
+        return Ok(validation_error_response(errors));
+    }
+
+    let item_id = path.into_inner();
+    let notes = sanitize_notes(body.notes.clone());
+
+    let result = sqlx::query(
+        "UPDATE items SET name = ?, quantity = ?, expiry_date = ?, notes = ? WHERE id = ?",
+    )
+    .bind(&body.name)
+    .bind(body.quantity)
+    .bind(&body.expiry_date)
+    .bind(&notes)
+    .bind(item_id)
+    .execute(db.as_ref())
+    .await;
+
+    match result {
+        Ok(result) if result.rows_affected() > 0 => {
+            let list_id: (i64,) = sqlx::query_as("SELECT list_id FROM items WHERE id = ?")
+                .bind(item_id)
+                .fetch_one(db.as_ref())
+                .await
+                .unwrap_or((0,));
+
+            Ok(HttpResponse::Ok().json(ItemResponse {
+                id: item_id,
+                list_id: list_id.0,
+                name: body.name.clone(),
+                quantity: body.quantity,
+                expiry_date: body.expiry_date.clone(),
+                notes,
+            }))
+        }
+        Ok(_) => Ok(HttpResponse::NotFound().finish()),
+        Err(_) => Ok(HttpResponse::InternalServerError().finish()),
+    }
+}
+
+pub async fn delete_item(db: web::Data<Pool<Sqlite>>, path: web::Path<i64>) -> Result<HttpResponse> {
+    let item_id = path.into_inner();
+
+    let result = sqlx::query("DELETE FROM items WHERE id = ?")
+        .bind(item_id)
+        .execute(db.as_ref())
+        .await;
+
+    match result {
+        Ok(result) if result.rows_affected() > 0 => Ok(HttpResponse::NoContent().finish()),
+        Ok(_) => Ok(HttpResponse::NotFound().finish()),
+        Err(_) => Ok(HttpResponse::InternalServerError().finish()),
+    }
+}
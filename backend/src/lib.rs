@@ -0,0 +1,86 @@
+use actix_web::{web, HttpResponse, Result};
+use serde::{Deserialize, Serialize};
+use sqlx::{Pool, Sqlite};
+
+pub mod assets;
+pub mod auth;
+pub mod config;
+pub mod graphql;
+pub mod items;
+pub mod reminders;
+
+pub use config::Config;
+pub use graphql::build_schema;
+pub use reminders::{spawn_reminder_scanner, ReminderStore};
+
+#[derive(Debug, Serialize, Deserialize, ts_rs::TS)]
+#[ts(export)]
+pub struct HealthResponse {
+    pub status: String,
+    pub message: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ts_rs::TS)]
+#[ts(export)]
+pub struct HelloResponse {
+    pub message: String,
+}
+
+async fn health_check(db: web::Data<Pool<Sqlite>>) -> Result<HttpResponse> {
+    // Test database connection
+    match sqlx::query("SELECT 1").fetch_one(db.as_ref()).await {
+        Ok(_) => Ok(HttpResponse::Ok().json(HealthResponse {
+            status: "ok".to_string(),
+            message: "Database connected".to_string(),
+        })),
+        Err(_) => Ok(HttpResponse::InternalServerError().json(HealthResponse {
+            status: "error".to_string(),
+            message: "Database connection failed".to_string(),
+        })),
+    }
+}
+
+async fn hello_world() -> Result<HttpResponse> {
+    Ok(HttpResponse::Ok().json(HelloResponse {
+        message: "Hello World from GroceryNana Backend!".to_string(),
+    }))
+}
+
+/// Registers every route shared by the real server and the integration
+/// tests, so both build the exact same `App`. Request handlers (and their
+/// `app_data`) are wired up by the caller; this only owns routing.
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.route("/", web::get().to(hello_world))
+        .route("/api/health", web::get().to(health_check))
+        .route("/api/register", web::post().to(auth::register))
+        .route("/api/login", web::post().to(auth::login))
+        // Grocery data itself is per-account, so these resources require a
+        // valid `Authorization: Bearer <token>` session. Wrapped per-resource
+        // (instead of a `web::scope("/api")`) so they can't swallow sibling
+        // `/api/*` resources like GraphQL registered below.
+        .service(
+            web::resource("/api/items")
+                .wrap(auth::AuthMiddleware)
+                .route(web::post().to(items::create_item))
+                .route(web::get().to(items::list_items)),
+        )
+        .service(
+            web::resource("/api/items/{id}")
+                .wrap(auth::AuthMiddleware)
+                .route(web::put().to(items::update_item))
+                .route(web::delete().to(items::delete_item)),
+        )
+        .service(
+            web::resource("/api/reminders")
+                .wrap(auth::AuthMiddleware)
+                .route(web::get().to(reminders::list_reminders)),
+        )
+        .service(
+            web::resource("/api/graphql").route(web::post().to(graphql::graphql_index)),
+        )
+        .service(
+            web::resource("/api/graphql/playground")
+                .route(web::get().to(graphql::graphql_playground)),
+        )
+        .route("/{filename:.*}", web::get().to(assets::spa_fallback));
+}
@@ -1,41 +1,9 @@
-use actix_cors::Cors;
-use actix_web::{web, App, HttpResponse, HttpServer, Result};
-use serde::{Deserialize, Serialize};
-use sqlx::{Pool, Sqlite, SqlitePool};
-use std::env;
-
-#[derive(Debug, Serialize, Deserialize, ts_rs::TS)]
-#[ts(export)]
-pub struct HealthResponse {
-    pub status: String,
-    pub message: String,
-}
+use std::time::Duration;
 
-#[derive(Debug, Serialize, Deserialize, ts_rs::TS)]
-#[ts(export)]
-pub struct HelloResponse {
-    pub message: String,
-}
-
-async fn health_check(db: web::Data<Pool<Sqlite>>) -> Result<HttpResponse> {
-    // Test database connection
-    match sqlx::query("SELECT 1").fetch_one(db.as_ref()).await {
-        Ok(_) => Ok(HttpResponse::Ok().json(HealthResponse {
-            status: "ok".to_string(),
-            message: "Database connected".to_string(),
-        })),
-        Err(_) => Ok(HttpResponse::InternalServerError().json(HealthResponse {
-            status: "error".to_string(),
-            message: "Database connection failed".to_string(),
-        })),
-    }
-}
-
-async fn hello_world() -> Result<HttpResponse> {
-    Ok(HttpResponse::Ok().json(HelloResponse {
-        message: "Hello World from GroceryNana Backend!".to_string(),
-    }))
-}
+use actix_cors::Cors;
+use actix_web::{web, App, HttpServer};
+use backend::{build_schema, configure, spawn_reminder_scanner, Config, ReminderStore};
+use sqlx::SqlitePool;
 
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
@@ -43,12 +11,10 @@ async fn main() -> std::io::Result<()> {
     dotenv::dotenv().ok();
     env_logger::init();
 
-    // Database setup
-    let database_url =
-        env::var("DATABASE_URL").unwrap_or_else(|_| "sqlite:./database.db".to_string());
+    let config = Config::load();
 
     // Create database pool
-    let pool = SqlitePool::connect(&database_url)
+    let pool = SqlitePool::connect(&config.database_url)
         .await
         .expect("Failed to create database pool");
 
@@ -58,7 +24,18 @@ async fn main() -> std::io::Result<()> {
         .await
         .expect("Failed to run migrations");
 
-    log::info!("Starting GroceryNana Backend server on http://localhost:8080");
+    let schema = build_schema(pool.clone());
+
+    let reminders = web::Data::new(ReminderStore::default());
+    spawn_reminder_scanner(
+        pool.clone(),
+        reminders.clone(),
+        Duration::from_secs(config.reminder_scan_interval_secs),
+        config.reminder_expiry_threshold_days,
+    );
+
+    let bind_address = config.bind_address();
+    log::info!("Starting GroceryNana Backend server on http://{bind_address}");
 
     HttpServer::new(move || {
         let cors = Cors::default()
@@ -69,12 +46,13 @@ async fn main() -> std::io::Result<()> {
 
         App::new()
             .app_data(web::Data::new(pool.clone()))
+            .app_data(web::Data::new(schema.clone()))
+            .app_data(reminders.clone())
             .wrap(cors)
             .wrap(actix_web::middleware::Logger::default())
-            .route("/", web::get().to(hello_world))
-            .route("/api/health", web::get().to(health_check))
+            .configure(configure)
     })
-    .bind("0.0.0.0:8080")?
+    .bind(bind_address)?
     .run()
     .await
 }
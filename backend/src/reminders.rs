@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration as StdDuration;
+
+use actix_web::{web, HttpResponse, Result};
+use chrono::{NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{Pool, Sqlite};
+
+pub type ItemId = i64;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ts_rs::TS)]
+#[ts(export)]
+pub struct ReminderState {
+    pub item_id: ItemId,
+    pub item_name: String,
+    pub expiry_date: String,
+    pub last_notified_at: String,
+}
+
+pub type ReminderStore = Mutex<HashMap<ItemId, ReminderState>>;
+
+/// Launched from `main()` before `HttpServer::new`. Wakes up every
+/// `scan_interval`, scans `items` for anything expiring within
+/// `expiry_threshold_days`, and records a `ReminderState` per item so
+/// `GET /api/reminders` can surface what's expiring soon. Both are sourced
+/// from `Config` so operators can tune them without a rebuild.
+pub fn spawn_reminder_scanner(
+    pool: Pool<Sqlite>,
+    reminders: web::Data<ReminderStore>,
+    scan_interval: StdDuration,
+    expiry_threshold_days: i64,
+) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(scan_interval);
+        loop {
+            interval.tick().await;
+            log::info!("Scanning items for upcoming expiry reminders");
+
+            match scan_expiring_items(&pool, expiry_threshold_days).await {
+                Ok(found) => {
+                    let mut store = reminders.lock().expect("reminder store lock poisoned");
+                    for state in found {
+                        store.insert(state.item_id, state);
+                    }
+                }
+                Err(err) => {
+                    log::error!("Failed to scan items for expiry reminders: {err}");
+                }
+            }
+        }
+    });
+}
+
+async fn scan_expiring_items(
+    pool: &Pool<Sqlite>,
+    expiry_threshold_days: i64,
+) -> Result<Vec<ReminderState>, sqlx::Error> {
+    let threshold = Utc::now().date_naive() + chrono::Duration::days(expiry_threshold_days);
+
+    let rows: Vec<(i64, String, String)> = sqlx::query_as(
+        "SELECT id, name, expiry_date FROM items WHERE expiry_date IS NOT NULL AND expiry_date <= ?",
+    )
+    .bind(threshold.to_string())
+    .fetch_all(pool)
+    .await?;
+
+    let now = Utc::now().to_rfc3339();
+    Ok(rows
+        .into_iter()
+        .filter(|(_, _, expiry_date)| NaiveDate::parse_from_str(expiry_date, "%Y-%m-%d").is_ok())
+        .map(|(item_id, item_name, expiry_date)| ReminderState {
+            item_id,
+            item_name,
+            expiry_date,
+            last_notified_at: now.clone(),
+        })
+        .collect())
+}
+
+pub async fn list_reminders(reminders: web::Data<ReminderStore>) -> Result<HttpResponse> {
+    let store = reminders.lock().expect("reminder store lock poisoned");
+    let reminders: Vec<ReminderState> = store.values().cloned().collect();
+    Ok(HttpResponse::Ok().json(reminders))
+}
@@ -0,0 +1,58 @@
+use actix_web::{test, web, App};
+use backend::{build_schema, configure, ReminderStore};
+use serde_json::{json, Value};
+use sqlx::sqlite::SqlitePoolOptions;
+use sqlx::{Pool, Sqlite};
+
+/// Spins up a fresh in-memory SQLite database, migrated the same way the
+/// real server migrates `./migrations`, seeded with one shopping list.
+async fn test_pool() -> Pool<Sqlite> {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .expect("failed to create in-memory test database");
+
+    sqlx::migrate!("./migrations")
+        .run(&pool)
+        .await
+        .expect("failed to run migrations");
+
+    sqlx::query("INSERT INTO shopping_lists (id, name) VALUES (1, 'Test List')")
+        .execute(&pool)
+        .await
+        .expect("failed to seed shopping list");
+
+    pool
+}
+
+/// `/api/graphql` must stay reachable without a session token: it's a
+/// sibling of the authenticated `/api/items`/`/api/reminders` resources, not
+/// nested under them, so a `web::scope("/api")` swallowing the whole prefix
+/// is a regression this test guards against.
+#[actix_web::test]
+async fn graphql_query_is_reachable_without_auth() {
+    let pool = test_pool().await;
+    let schema = build_schema(pool.clone());
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(pool.clone()))
+            .app_data(web::Data::new(schema))
+            .app_data(web::Data::new(ReminderStore::default()))
+            .configure(configure),
+    )
+    .await;
+
+    let req = test::TestRequest::post()
+        .uri("/api/graphql")
+        .set_json(json!({"query": "{ lists { id name } }"}))
+        .to_request();
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), 200);
+
+    let body: Value = test::read_body_json(res).await;
+    let lists = body["data"]["lists"]
+        .as_array()
+        .expect("response has a data.lists array");
+    assert!(lists.iter().any(|list| list["name"] == "Test List"));
+}
@@ -0,0 +1,166 @@
+use actix_web::{test, web, App};
+use backend::{build_schema, configure, ReminderStore};
+use serde_json::{json, Value};
+use sqlx::sqlite::SqlitePoolOptions;
+use sqlx::{Pool, Sqlite};
+
+/// Spins up a fresh in-memory SQLite database, migrated the same way the
+/// real server migrates `./migrations`, seeded with one shopping list for
+/// items to belong to.
+async fn test_pool() -> Pool<Sqlite> {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .expect("failed to create in-memory test database");
+
+    sqlx::migrate!("./migrations")
+        .run(&pool)
+        .await
+        .expect("failed to run migrations");
+
+    sqlx::query("INSERT INTO shopping_lists (id, name) VALUES (1, 'Test List')")
+        .execute(&pool)
+        .await
+        .expect("failed to seed shopping list");
+
+    pool
+}
+
+macro_rules! test_app {
+    ($pool:expr) => {{
+        let schema = build_schema($pool.clone());
+        test::init_service(
+            App::new()
+                .app_data(web::Data::new($pool.clone()))
+                .app_data(web::Data::new(schema))
+                .app_data(web::Data::new(ReminderStore::default()))
+                .configure(configure),
+        )
+        .await
+    }};
+}
+
+/// Registers and logs in a throwaway user against `app`, returning an
+/// `Authorization` header value ready to attach to requests against the
+/// protected `/api/items`/`/api/reminders` scope.
+macro_rules! auth_header {
+    ($app:expr) => {{
+        let register_req = test::TestRequest::post()
+            .uri("/api/register")
+            .set_json(json!({"email": "nana@example.com", "password": "correcthorsebatterystaple"}))
+            .to_request();
+        test::call_service(&$app, register_req).await;
+
+        let login_req = test::TestRequest::post()
+            .uri("/api/login")
+            .set_json(json!({"email": "nana@example.com", "password": "correcthorsebatterystaple"}))
+            .to_request();
+        let login_res: Value = test::call_and_read_body_json(&$app, login_req).await;
+        let token = login_res["token"]
+            .as_str()
+            .expect("login response has a token")
+            .to_string();
+
+        ("Authorization", format!("Bearer {token}"))
+    }};
+}
+
+#[actix_web::test]
+async fn create_fetch_delete_item_round_trip() {
+    let pool = test_pool().await;
+    let app = test_app!(pool);
+    let auth = auth_header!(app);
+
+    let create_req = test::TestRequest::post()
+        .uri("/api/items")
+        .insert_header(auth.clone())
+        .set_json(json!({
+            "name": "Milk",
+            "quantity": 2,
+            "list_id": 1,
+            "expiry_date": null,
+            "notes": null,
+        }))
+        .to_request();
+    let created: Value = test::call_and_read_body_json(&app, create_req).await;
+    let item_id = created["id"].as_i64().expect("created item has an id");
+    assert_eq!(created["name"], "Milk");
+
+    let list_req = test::TestRequest::get()
+        .uri("/api/items")
+        .insert_header(auth.clone())
+        .to_request();
+    let items: Value = test::call_and_read_body_json(&app, list_req).await;
+    assert!(items
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|item| item["id"] == item_id));
+
+    let delete_req = test::TestRequest::delete()
+        .uri(&format!("/api/items/{item_id}"))
+        .insert_header(auth.clone())
+        .to_request();
+    let delete_res = test::call_service(&app, delete_req).await;
+    assert_eq!(delete_res.status(), 204);
+
+    let list_req = test::TestRequest::get()
+        .uri("/api/items")
+        .insert_header(auth.clone())
+        .to_request();
+    let items: Value = test::call_and_read_body_json(&app, list_req).await;
+    assert!(!items
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|item| item["id"] == item_id));
+}
+
+#[actix_web::test]
+async fn rejects_invalid_item() {
+    let pool = test_pool().await;
+    let app = test_app!(pool);
+    let auth = auth_header!(app);
+
+    let req = test::TestRequest::post()
+        .uri("/api/items")
+        .insert_header(auth)
+        .set_json(json!({
+            "name": "",
+            "quantity": -1,
+            "list_id": 1,
+        }))
+        .to_request();
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), 400);
+}
+
+#[actix_web::test]
+async fn rejects_requests_without_a_session_token() {
+    let pool = test_pool().await;
+    let app = test_app!(pool);
+
+    let req = test::TestRequest::get().uri("/api/items").to_request();
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), 401);
+}
+
+#[actix_web::test]
+async fn rejects_item_for_nonexistent_list() {
+    let pool = test_pool().await;
+    let app = test_app!(pool);
+    let auth = auth_header!(app);
+
+    let req = test::TestRequest::post()
+        .uri("/api/items")
+        .insert_header(auth)
+        .set_json(json!({
+            "name": "Milk",
+            "quantity": 1,
+            "list_id": 999,
+        }))
+        .to_request();
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), 400);
+}